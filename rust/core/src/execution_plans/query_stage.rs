@@ -33,15 +33,24 @@ pub struct QueryStageExec {
     pub stage_id: usize,
     /// Physical execution plan for this query stage
     pub child: Arc<dyn ExecutionPlan>,
+    /// Partitioning of the data produced by this query stage, with any hash expressions
+    /// expressed in terms of the output schema of the stage
+    pub output_partitioning: Partitioning,
 }
 
 impl QueryStageExec {
     /// Create a new query stage
-    pub fn try_new(job_id: String, stage_id: usize, child: Arc<dyn ExecutionPlan>) -> Result<Self> {
+    pub fn try_new(
+        job_id: String,
+        stage_id: usize,
+        child: Arc<dyn ExecutionPlan>,
+        output_partitioning: Partitioning,
+    ) -> Result<Self> {
         Ok(Self {
             job_id,
             stage_id,
             child,
+            output_partitioning,
         })
     }
 }
@@ -57,7 +66,7 @@ impl ExecutionPlan for QueryStageExec {
     }
 
     fn output_partitioning(&self) -> Partitioning {
-        self.child.output_partitioning()
+        self.output_partitioning.clone()
     }
 
     fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
@@ -73,6 +82,7 @@ impl ExecutionPlan for QueryStageExec {
             self.job_id.clone(),
             self.stage_id,
             children[0].clone(),
+            self.output_partitioning.clone(),
         )?))
     }
 
@@ -38,17 +38,21 @@ pub struct UnresolvedShuffleExec {
     // The schema this node will have once it is replaced with a ShuffleReaderExec
     pub schema: SchemaRef,
 
-    // The partition count this node will have once it is replaced with a ShuffleReaderExec
-    pub partition_count: usize,
+    // The partitioning of the shuffled data, as produced by the query stages
+    pub output_partitioning: Partitioning,
 }
 
 impl UnresolvedShuffleExec {
     /// Create a new UnresolvedShuffleExec
-    pub fn new(query_stage_ids: Vec<usize>, schema: SchemaRef, partition_count: usize) -> Self {
+    pub fn new(
+        query_stage_ids: Vec<usize>,
+        schema: SchemaRef,
+        output_partitioning: Partitioning,
+    ) -> Self {
         Self {
             query_stage_ids,
             schema,
-            partition_count,
+            output_partitioning,
         }
     }
 }
@@ -64,7 +68,7 @@ impl ExecutionPlan for UnresolvedShuffleExec {
     }
 
     fn output_partitioning(&self) -> Partitioning {
-        Partitioning::UnknownPartitioning(self.partition_count)
+        self.output_partitioning.clone()
     }
 
     fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
@@ -44,13 +44,15 @@ use datafusion::physical_plan::{
     limit::{GlobalLimitExec, LocalLimitExec},
     parquet::ParquetExec,
     projection::ProjectionExec,
+    repartition::RepartitionExec,
     sort::{SortExec, SortOptions},
 };
-use datafusion::physical_plan::{AggregateExpr, ExecutionPlan, PhysicalExpr};
+use datafusion::physical_plan::{AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr};
 use datafusion::prelude::CsvReadOptions;
 use log::debug;
 use protobuf::logical_expr_node::ExprType;
 use protobuf::physical_plan_node::PhysicalPlanType;
+use protobuf::repartition_exec_node::PartitionMethod;
 
 impl TryInto<Arc<dyn ExecutionPlan>> for &protobuf::PhysicalPlanNode {
     type Error = BallistaError;
@@ -296,6 +298,12 @@ impl TryInto<Arc<dyn ExecutionPlan>> for &protobuf::PhysicalPlanNode {
             }
             PhysicalPlanType::Unresolved(unresolved_shuffle) => {
                 let schema = Arc::new(convert_required!(unresolved_shuffle.schema)?);
+                let output_partitioning = match &unresolved_shuffle.hash {
+                    Some(hash) => hash_repartition_from_proto(hash, &schema)?,
+                    None => Partitioning::UnknownPartitioning(
+                        unresolved_shuffle.partition_count as usize,
+                    ),
+                };
                 Ok(Arc::new(UnresolvedShuffleExec {
                     query_stage_ids: unresolved_shuffle
                         .query_stage_ids
@@ -303,13 +311,46 @@ impl TryInto<Arc<dyn ExecutionPlan>> for &protobuf::PhysicalPlanNode {
                         .map(|id| *id as usize)
                         .collect(),
                     schema,
-                    partition_count: unresolved_shuffle.partition_count as usize,
+                    output_partitioning,
                 }))
             }
+            PhysicalPlanType::Repartition(repartition) => {
+                let input: Arc<dyn ExecutionPlan> = convert_box_required!(repartition.input)?;
+                let partition_method = repartition.partition_method.as_ref().ok_or_else(|| {
+                    proto_error(format!(
+                        "Received a RepartitionExecNode message with no partition method {:?}",
+                        self
+                    ))
+                })?;
+                let partitioning = match partition_method {
+                    PartitionMethod::RoundRobin(partition_count) => {
+                        Partitioning::RoundRobinBatch(*partition_count as usize)
+                    }
+                    PartitionMethod::Hash(hash) => {
+                        hash_repartition_from_proto(hash, &input.schema())?
+                    }
+                    PartitionMethod::Unknown(partition_count) => {
+                        Partitioning::UnknownPartitioning(*partition_count as usize)
+                    }
+                };
+                Ok(Arc::new(RepartitionExec::try_new(input, partitioning)?))
+            }
         }
     }
 }
 
+fn hash_repartition_from_proto(
+    hash: &protobuf::PhysicalHashRepartition,
+    schema: &Schema,
+) -> Result<Partitioning, BallistaError> {
+    let exprs = hash
+        .hash_expr
+        .iter()
+        .map(|expr| compile_expr(expr, schema))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Partitioning::Hash(exprs, hash.partition_count as usize))
+}
+
 fn compile_expr(
     expr: &protobuf::LogicalExprNode,
     schema: &Schema,
@@ -28,12 +28,14 @@ mod roundtrip_tests {
         hash_aggregate::{AggregateMode, HashAggregateExec},
         hash_join::HashJoinExec,
         limit::{GlobalLimitExec, LocalLimitExec},
+        repartition::RepartitionExec,
         sort::SortExec,
         ExecutionPlan,
     };
     use datafusion::physical_plan::{AggregateExpr, Distribution, Partitioning, PhysicalExpr};
 
     use super::super::super::error::Result;
+    use super::super::super::execution_plans::UnresolvedShuffleExec;
     use super::super::protobuf;
 
     fn roundtrip_test(exec_plan: Arc<dyn ExecutionPlan>) -> Result<()> {
@@ -167,4 +169,36 @@ mod roundtrip_tests {
             Arc::new(EmptyExec::new(false, schema)),
         )?))
     }
+
+    #[test]
+    fn roundtrip_repartition_hash() -> Result<()> {
+        use arrow::datatypes::{DataType, Field, Schema};
+        let field_a = Field::new("a", DataType::Int64, false);
+        let field_b = Field::new("b", DataType::Int64, false);
+        let schema = Arc::new(Schema::new(vec![field_a, field_b]));
+        roundtrip_test(Arc::new(RepartitionExec::try_new(
+            Arc::new(EmptyExec::new(false, schema)),
+            Partitioning::Hash(vec![col("b"), col("a")], 4),
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_repartition_round_robin() -> Result<()> {
+        roundtrip_test(Arc::new(RepartitionExec::try_new(
+            Arc::new(EmptyExec::new(false, Arc::new(Schema::empty()))),
+            Partitioning::RoundRobinBatch(4),
+        )?))
+    }
+
+    #[test]
+    fn roundtrip_unresolved_shuffle_hash() -> Result<()> {
+        use arrow::datatypes::{DataType, Field, Schema};
+        let field_a = Field::new("a", DataType::Int64, false);
+        let schema = Arc::new(Schema::new(vec![field_a]));
+        roundtrip_test(Arc::new(UnresolvedShuffleExec::new(
+            vec![1, 2],
+            schema,
+            Partitioning::Hash(vec![col("a")], 4),
+        )))
+    }
 }
@@ -35,6 +35,7 @@ use datafusion::physical_plan::hash_utils::JoinType;
 use datafusion::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use datafusion::physical_plan::parquet::ParquetExec;
 use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sort::SortExec;
 use datafusion::{
     physical_plan::expressions::{Count, Literal},
@@ -45,10 +46,11 @@ use datafusion::physical_plan::{
     empty::EmptyExec,
     expressions::{Avg, BinaryExpr, Column, Sum},
 };
-use datafusion::physical_plan::{AggregateExpr, ExecutionPlan, PhysicalExpr};
+use datafusion::physical_plan::{AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr};
 
 use datafusion::physical_plan::hash_aggregate::HashAggregateExec;
 use protobuf::physical_plan_node::PhysicalPlanType;
+use protobuf::repartition_exec_node::PartitionMethod;
 
 use crate::execution_plans::{ShuffleReaderExec, UnresolvedShuffleExec};
 use crate::serde::{protobuf, BallistaError};
@@ -295,15 +297,43 @@ impl TryInto<protobuf::PhysicalPlanNode> for Arc<dyn ExecutionPlan> {
                 ))),
             })
         } else if let Some(exec) = plan.downcast_ref::<UnresolvedShuffleExec>() {
+            let hash = match &exec.output_partitioning {
+                Partitioning::Hash(exprs, partition_count) => {
+                    Some(hash_repartition_to_proto(exprs, *partition_count)?)
+                }
+                _ => None,
+            };
             Ok(protobuf::PhysicalPlanNode {
                 physical_plan_type: Some(PhysicalPlanType::Unresolved(
                     protobuf::UnresolvedShuffleExecNode {
                         query_stage_ids: exec.query_stage_ids.iter().map(|id| *id as u32).collect(),
                         schema: Some(exec.schema().as_ref().into()),
-                        partition_count: exec.partition_count as u32,
+                        partition_count: exec.output_partitioning.partition_count() as u32,
+                        hash,
                     },
                 )),
             })
+        } else if let Some(exec) = plan.downcast_ref::<RepartitionExec>() {
+            let input: protobuf::PhysicalPlanNode = exec.children()[0].clone().try_into()?;
+            let partition_method = match exec.output_partitioning() {
+                Partitioning::RoundRobinBatch(partition_count) => {
+                    PartitionMethod::RoundRobin(partition_count as u64)
+                }
+                Partitioning::Hash(exprs, partition_count) => {
+                    PartitionMethod::Hash(hash_repartition_to_proto(&exprs, partition_count)?)
+                }
+                Partitioning::UnknownPartitioning(partition_count) => {
+                    PartitionMethod::Unknown(partition_count as u64)
+                }
+            };
+            Ok(protobuf::PhysicalPlanNode {
+                physical_plan_type: Some(PhysicalPlanType::Repartition(Box::new(
+                    protobuf::RepartitionExecNode {
+                        input: Some(Box::new(input)),
+                        partition_method: Some(partition_method),
+                    },
+                ))),
+            })
         } else {
             Err(BallistaError::General(format!(
                 "physical plan to_proto unsupported plan {:?}",
@@ -313,6 +343,19 @@ impl TryInto<protobuf::PhysicalPlanNode> for Arc<dyn ExecutionPlan> {
     }
 }
 
+fn hash_repartition_to_proto(
+    exprs: &[Arc<dyn PhysicalExpr>],
+    partition_count: usize,
+) -> Result<protobuf::PhysicalHashRepartition, BallistaError> {
+    Ok(protobuf::PhysicalHashRepartition {
+        hash_expr: exprs
+            .iter()
+            .map(|expr| expr.clone().try_into())
+            .collect::<Result<Vec<_>, BallistaError>>()?,
+        partition_count: partition_count as u64,
+    })
+}
+
 impl TryInto<protobuf::LogicalExprNode> for Arc<dyn AggregateExpr> {
     type Error = BallistaError;
 
@@ -39,8 +39,11 @@ use datafusion::physical_plan::hash_join::HashJoinExec;
 use datafusion::physical_plan::merge::MergeExec;
 use datafusion::physical_plan::parquet::ParquetExec;
 use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::repartition::RepartitionExec;
 use datafusion::physical_plan::sort::SortExec;
-use datafusion::physical_plan::{AggregateExpr, ExecutionPlan, PhysicalExpr, RecordBatchStream};
+use datafusion::physical_plan::{
+    AggregateExpr, ExecutionPlan, Partitioning, PhysicalExpr, RecordBatchStream,
+};
 use futures::StreamExt;
 
 /// Summary of executed partition
@@ -232,13 +235,24 @@ pub fn format_plan(plan: &dyn ExecutionPlan, indent: usize) -> Result<String> {
         )
     } else if let Some(exec) = plan.as_any().downcast_ref::<FilterExec>() {
         format!("FilterExec: {}", format_expr(exec.predicate().as_ref()))
+    } else if let Some(exec) = plan.as_any().downcast_ref::<RepartitionExec>() {
+        format!(
+            "RepartitionExec: partitioning={}",
+            format_partitioning(&exec.output_partitioning())
+        )
     } else if let Some(exec) = plan.as_any().downcast_ref::<QueryStageExec>() {
         format!(
-            "QueryStageExec: job={}, stage={}",
-            exec.job_id, exec.stage_id
+            "QueryStageExec: job={}, stage={}, partitioning={}",
+            exec.job_id,
+            exec.stage_id,
+            format_partitioning(&exec.output_partitioning)
         )
     } else if let Some(exec) = plan.as_any().downcast_ref::<UnresolvedShuffleExec>() {
-        format!("UnresolvedShuffleExec: stages={:?}", exec.query_stage_ids)
+        format!(
+            "UnresolvedShuffleExec: stages={:?}, partitioning={}",
+            exec.query_stage_ids,
+            format_partitioning(&exec.output_partitioning)
+        )
     } else if let Some(exec) = plan.as_any().downcast_ref::<CoalesceBatchesExec>() {
         format!(
             "CoalesceBatchesExec: batchSize={}",
@@ -289,6 +303,25 @@ pub fn format_expr(expr: &dyn PhysicalExpr) -> String {
     }
 }
 
+pub fn format_partitioning(partitioning: &Partitioning) -> String {
+    match partitioning {
+        Partitioning::Hash(exprs, partition_count) => format!(
+            "Hash({:?}, {})",
+            exprs
+                .iter()
+                .map(|e| format_expr(e.as_ref()))
+                .collect::<Vec<String>>(),
+            partition_count
+        ),
+        Partitioning::RoundRobinBatch(partition_count) => {
+            format!("RoundRobinBatch({})", partition_count)
+        }
+        Partitioning::UnknownPartitioning(partition_count) => {
+            format!("UnknownPartitioning({})", partition_count)
+        }
+    }
+}
+
 pub fn produce_diagram(filename: &str, stages: &[Arc<QueryStageExec>]) -> Result<()> {
     let write_file = File::create(filename)?;
     let mut w = BufWriter::new(&write_file);
@@ -350,6 +383,8 @@ fn build_exec_plan_diagram(
         "CoalesceBatchesExec"
     } else if plan.as_any().downcast_ref::<MergeExec>().is_some() {
         "MergeExec"
+    } else if plan.as_any().downcast_ref::<RepartitionExec>().is_some() {
+        "RepartitionExec"
     } else {
         println!("Unknown: {:?}", plan);
         "Unknown"
@@ -30,12 +30,19 @@ use ballista_core::{
     serde::scheduler::PartitionLocation,
 };
 
+use arrow::datatypes::Schema;
 use ballista_core::utils::format_plan;
 use datafusion::execution::context::ExecutionContext;
+use datafusion::physical_plan::coalesce_batches::CoalesceBatchesExec;
+use datafusion::physical_plan::expressions::Column;
+use datafusion::physical_plan::filter::FilterExec;
 use datafusion::physical_plan::hash_aggregate::{AggregateMode, HashAggregateExec};
 use datafusion::physical_plan::hash_join::HashJoinExec;
+use datafusion::physical_plan::hash_utils::JoinType;
 use datafusion::physical_plan::merge::MergeExec;
-use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::projection::ProjectionExec;
+use datafusion::physical_plan::repartition::RepartitionExec;
+use datafusion::physical_plan::{ExecutionPlan, Partitioning, PhysicalExpr};
 use log::{debug, info};
 use std::time::Instant;
 
@@ -45,6 +52,8 @@ type PartialQueryStageResult = (Arc<dyn ExecutionPlan>, Vec<Arc<QueryStageExec>>
 pub struct DistributedPlanner {
     executors: Vec<ExecutorMeta>,
     next_stage_id: usize,
+    /// Remove repartitions whose input is already partitioned as required
+    eliminate_redundant_shuffles: bool,
 }
 
 impl DistributedPlanner {
@@ -57,6 +66,7 @@ impl DistributedPlanner {
             Ok(Self {
                 executors,
                 next_stage_id: 0,
+                eliminate_redundant_shuffles: true,
             })
         }
     }
@@ -90,7 +100,10 @@ impl DistributedPlanner {
 
     /// Returns a vector of ExecutionPlans, where the root node is a [QueryStageExec].
     /// Plans that depend on the input of other plans will have leaf nodes of type [UnresolvedShuffleExec].
-    /// A [QueryStageExec] is created whenever the partitioning changes.
+    /// A [QueryStageExec] is created whenever the partitioning changes. A [RepartitionExec] whose
+    /// input is already partitioned the same way is removed rather than turned into a shuffle.
+    /// DataFusion doesn't plan hash repartitions for joins or aggregates, so this only applies to
+    /// repartitions that are explicitly part of the query.
     ///
     /// Returns an empty vector if the execution_plan doesn't need to be sliced into several stages.
     pub fn plan_query_stages(
@@ -137,12 +150,12 @@ impl DistributedPlanner {
             let query_stage = create_query_stage(
                 job_id.to_string(),
                 self.next_stage_id(),
-                merge.children()[0].clone(),
+                children[0].clone(),
             )?;
             let unresolved_shuffle = Arc::new(UnresolvedShuffleExec::new(
                 vec![query_stage.stage_id],
                 query_stage.schema(),
-                query_stage.output_partitioning().partition_count(),
+                query_stage.output_partitioning(),
             ));
             stages.push(query_stage);
             Ok((merge.with_new_children(vec![unresolved_shuffle])?, stages))
@@ -161,7 +174,7 @@ impl DistributedPlanner {
                         new_children.push(Arc::new(UnresolvedShuffleExec::new(
                             vec![new_stage.stage_id],
                             new_stage.schema().clone(),
-                            new_stage.output_partitioning().partition_count(),
+                            new_stage.output_partitioning(),
                         )));
                         stages.push(new_stage);
                     }
@@ -171,10 +184,28 @@ impl DistributedPlanner {
             }
         } else if let Some(join) = execution_plan.as_any().downcast_ref::<HashJoinExec>() {
             Ok((join.with_new_children(children)?, stages))
+        } else if self.eliminate_redundant_shuffles
+            && execution_plan.as_any().is::<RepartitionExec>()
+            && partitioning_satisfies(
+                &output_partitioning(children[0].as_ref()),
+                &execution_plan.output_partitioning(),
+            )
+        {
+            // the input is already partitioned as required, so the repartition is redundant and
+            // the consuming operators can stay in the same query stage as the input
+            Ok((children[0].clone(), stages))
         } else {
-            // TODO check for compatible partitioning schema, not just count
-            if execution_plan.output_partitioning().partition_count()
-                != children[0].output_partitioning().partition_count()
+            // a hash repartition reads every partition of its input, so the input is
+            // materialized as a query stage rather than recomputed by each consuming task
+            let is_hash_repartition = execution_plan.as_any().is::<RepartitionExec>()
+                && matches!(
+                    execution_plan.output_partitioning(),
+                    Partitioning::Hash(_, _)
+                )
+                && !children[0].as_any().is::<UnresolvedShuffleExec>();
+            if is_hash_repartition
+                || execution_plan.output_partitioning().partition_count()
+                    != children[0].output_partitioning().partition_count()
             {
                 let mut new_children: Vec<Arc<dyn ExecutionPlan>> = vec![];
                 for child in &children {
@@ -186,7 +217,7 @@ impl DistributedPlanner {
                     new_children.push(Arc::new(UnresolvedShuffleExec::new(
                         vec![new_stage.stage_id],
                         new_stage.schema().clone(),
-                        new_stage.output_partitioning().partition_count(),
+                        new_stage.output_partitioning(),
                     )));
                     stages.push(new_stage);
                 }
@@ -273,7 +304,145 @@ fn create_query_stage(
     stage_id: usize,
     plan: Arc<dyn ExecutionPlan>,
 ) -> Result<Arc<QueryStageExec>> {
-    Ok(Arc::new(QueryStageExec::try_new(job_id, stage_id, plan)?))
+    let output_partitioning = output_partitioning(plan.as_ref());
+    Ok(Arc::new(QueryStageExec::try_new(
+        job_id,
+        stage_id,
+        plan,
+        output_partitioning,
+    )?))
+}
+
+/// Returns the output partitioning of a plan with any hash expressions expressed in terms of
+/// the output schema of the plan.
+///
+/// DataFusion operators such as [ProjectionExec] report the partitioning of their input as-is,
+/// which is wrong once columns are renamed, so the column mapping is tracked here instead. Hash
+/// partitioning that can't be tracked through an operator is reported as unknown.
+fn output_partitioning(plan: &dyn ExecutionPlan) -> Partitioning {
+    if let Some(projection) = plan.as_any().downcast_ref::<ProjectionExec>() {
+        project_partitioning(
+            output_partitioning(projection.input().as_ref()),
+            projection.expr(),
+        )
+    } else if let Some(agg) = plan.as_any().downcast_ref::<HashAggregateExec>() {
+        // aggregating each partition keeps the grouping columns where they were
+        project_partitioning(
+            output_partitioning(agg.children()[0].as_ref()),
+            agg.group_expr(),
+        )
+    } else if let Some(join) = plan.as_any().downcast_ref::<HashJoinExec>() {
+        // the left side is collected and the output follows the partitioning of the right side,
+        // which only holds for inner joins since outer joins emit unmatched rows separately.
+        // Columns are resolved by name, so a hash column is only usable if its name is unique
+        // in the join output.
+        match (join.join_type(), output_partitioning(join.right().as_ref())) {
+            (JoinType::Inner, Partitioning::Hash(exprs, partition_count)) => {
+                let schema = join.schema();
+                if exprs
+                    .iter()
+                    .all(|expr| is_unique_column(expr.as_ref(), &schema))
+                {
+                    Partitioning::Hash(exprs, partition_count)
+                } else {
+                    Partitioning::UnknownPartitioning(partition_count)
+                }
+            }
+            (JoinType::Inner, partitioning) => partitioning,
+            (_, partitioning) => Partitioning::UnknownPartitioning(partitioning.partition_count()),
+        }
+    } else if let Some(filter) = plan.as_any().downcast_ref::<FilterExec>() {
+        output_partitioning(filter.input().as_ref())
+    } else if let Some(coalesce) = plan.as_any().downcast_ref::<CoalesceBatchesExec>() {
+        output_partitioning(coalesce.input().as_ref())
+    } else if plan.as_any().is::<RepartitionExec>()
+        || plan.as_any().is::<UnresolvedShuffleExec>()
+        || plan.as_any().is::<QueryStageExec>()
+    {
+        // these operators define their own partitioning in terms of their output schema
+        plan.output_partitioning()
+    } else {
+        match plan.output_partitioning() {
+            Partitioning::Hash(_, partition_count) => {
+                Partitioning::UnknownPartitioning(partition_count)
+            }
+            partitioning => partitioning,
+        }
+    }
+}
+
+/// Rewrites hash partitioning in terms of the output of a projection. The partitioning becomes
+/// unknown if any of the hash columns is not projected.
+fn project_partitioning(
+    partitioning: Partitioning,
+    projection: &[(Arc<dyn PhysicalExpr>, String)],
+) -> Partitioning {
+    match partitioning {
+        Partitioning::Hash(exprs, partition_count) => {
+            let projected_exprs = exprs
+                .iter()
+                .map(|expr| {
+                    let column = expr.as_any().downcast_ref::<Column>()?;
+                    projection.iter().find_map(|(projected_expr, alias)| {
+                        match projected_expr.as_any().downcast_ref::<Column>() {
+                            Some(projected) if projected.name() == column.name() => {
+                                Some(Arc::new(Column::new(alias)) as Arc<dyn PhysicalExpr>)
+                            }
+                            _ => None,
+                        }
+                    })
+                })
+                .collect::<Option<Vec<_>>>();
+            match projected_exprs {
+                Some(exprs) => Partitioning::Hash(exprs, partition_count),
+                None => Partitioning::UnknownPartitioning(partition_count),
+            }
+        }
+        partitioning => partitioning,
+    }
+}
+
+/// Returns true if data partitioned as `actual` is already partitioned as `required`, i.e.
+/// hash partitioned on the same columns in the same order into the same number of partitions.
+fn partitioning_satisfies(actual: &Partitioning, required: &Partitioning) -> bool {
+    match (actual, required) {
+        (
+            Partitioning::Hash(actual_exprs, actual_count),
+            Partitioning::Hash(required_exprs, required_count),
+        ) => {
+            actual_count == required_count
+                && actual_exprs.len() == required_exprs.len()
+                && actual_exprs
+                    .iter()
+                    .zip(required_exprs.iter())
+                    .all(|(a, b)| is_same_column(a.as_ref(), b.as_ref()))
+        }
+        _ => false,
+    }
+}
+
+fn is_unique_column(expr: &dyn PhysicalExpr, schema: &Schema) -> bool {
+    match expr.as_any().downcast_ref::<Column>() {
+        Some(column) => {
+            schema
+                .fields()
+                .iter()
+                .filter(|field| field.name() == column.name())
+                .count()
+                == 1
+        }
+        None => false,
+    }
+}
+
+fn is_same_column(a: &dyn PhysicalExpr, b: &dyn PhysicalExpr) -> bool {
+    match (
+        a.as_any().downcast_ref::<Column>(),
+        b.as_any().downcast_ref::<Column>(),
+    ) {
+        (Some(a), Some(b)) => a.name() == b.name(),
+        _ => false,
+    }
 }
 
 /// Execute a query stage by sending each partition to an executor
@@ -362,16 +531,25 @@ async fn execute_query_stage(
 mod test {
     use crate::planner::DistributedPlanner;
     use crate::test_utils::datafusion_test_context;
+    use arrow::array::Int32Array;
+    use arrow::record_batch::RecordBatch;
+    use arrow::util::pretty::pretty_format_batches;
     use ballista_core::error::BallistaError;
-    use ballista_core::execution_plans::UnresolvedShuffleExec;
+    use ballista_core::execution_plans::{QueryStageExec, UnresolvedShuffleExec};
     use ballista_core::serde::protobuf;
     use ballista_core::serde::scheduler::ExecutorMeta;
-    use ballista_core::utils::format_plan;
+    use ballista_core::utils::{collect_stream, format_plan};
+    use datafusion::logical_plan::{col, count, JoinType, Partitioning};
+    use datafusion::physical_plan::collect;
+    use datafusion::physical_plan::expressions::Column;
     use datafusion::physical_plan::hash_aggregate::HashAggregateExec;
+    use datafusion::physical_plan::memory::MemoryExec;
     use datafusion::physical_plan::merge::MergeExec;
     use datafusion::physical_plan::projection::ProjectionExec;
+    use datafusion::physical_plan::repartition::RepartitionExec;
     use datafusion::physical_plan::sort::SortExec;
-    use datafusion::physical_plan::ExecutionPlan;
+    use datafusion::physical_plan::{ExecutionPlan, Partitioning as PhysicalPartitioning};
+    use std::collections::HashMap;
     use std::convert::TryInto;
     use std::sync::Arc;
     use uuid::Uuid;
@@ -410,19 +588,19 @@ mod test {
         }
 
         /* Expected result:
-        QueryStageExec: job=f011432e-e424-4016-915d-e3d8b84f6dbd, stage=1
+        QueryStageExec: job=f011432e-e424-4016-915d-e3d8b84f6dbd, stage=1, partitioning=UnknownPartitioning(2)
          HashAggregateExec: groupBy=["l_returnflag"], aggrExpr=["SUM(l_extendedprice Multiply Int64(1)) [\"l_extendedprice * CAST(1 AS Float64)\"]"]
           CsvExec: testdata/lineitem; partitions=2
 
-        QueryStageExec: job=f011432e-e424-4016-915d-e3d8b84f6dbd, stage=2
+        QueryStageExec: job=f011432e-e424-4016-915d-e3d8b84f6dbd, stage=2, partitioning=UnknownPartitioning(1)
          MergeExec
-          UnresolvedShuffleExec: stages=[1]
+          UnresolvedShuffleExec: stages=[1], partitioning=UnknownPartitioning(2)
 
-        QueryStageExec: job=f011432e-e424-4016-915d-e3d8b84f6dbd, stage=3
+        QueryStageExec: job=f011432e-e424-4016-915d-e3d8b84f6dbd, stage=3, partitioning=UnknownPartitioning(1)
          SortExec { input: ProjectionExec { expr: [(Column { name: "l_returnflag" }, "l_returnflag"), (Column { name: "SUM(l_ext
           ProjectionExec { expr: [(Column { name: "l_returnflag" }, "l_returnflag"), (Column { name: "SUM(l_extendedprice Multip
            HashAggregateExec: groupBy=["l_returnflag"], aggrExpr=["SUM(l_extendedprice Multiply Int64(1)) [\"l_extendedprice * CAST(1 AS Float64)\"]"]
-            UnresolvedShuffleExec: stages=[2]
+            UnresolvedShuffleExec: stages=[2], partitioning=UnknownPartitioning(1)
         */
 
         let sort = stages[2].children()[0].clone();
@@ -460,6 +638,230 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn join_then_aggregate_on_same_key() -> Result<(), BallistaError> {
+        let plan = join_then_aggregate_plan(4)?;
+        let job_id = Uuid::new_v4().to_string();
+        let stages = test_planner()?.plan_query_stages(&job_id, plan.clone())?;
+        let explain = format_stages(&stages)?.replace(&job_id, "<job>");
+        println!("{}", explain);
+
+        // the repartition on the renamed join key is satisfied by the repartition before the
+        // join, so the join and the partial aggregate run in the same stage
+        let expected = vec![
+            "QueryStageExec: job=<job>, stage=1, partitioning=UnknownPartitioning(2)",
+            "  CsvExec: testdata/lineitem; partitions=2",
+            "",
+            "QueryStageExec: job=<job>, stage=2, partitioning=Hash([\"orderkey\"], 4)",
+            "  HashAggregateExec: groupBy=[\"orderkey\"], aggrExpr=[\"COUNT(l_quantity) [\\\"l_quantity\\\"]\"]",
+            "    CoalesceBatchesExec: batchSize=8192",
+            "      ProjectionExec { expr: [(Column { name: \"l_orderkey\" }, \"orderkey\"), (Column { name: \"l_quantity\" }, \"l_quantity\")], sch",
+            "        CoalesceBatchesExec: batchSize=8192",
+            "          HashJoinExec: joinType=Inner, on=[(\"o_orderkey\", \"l_orderkey\")]",
+            "            CsvExec: testdata/orders; partitions=1",
+            "            CoalesceBatchesExec: batchSize=8192",
+            "              RepartitionExec: partitioning=Hash([\"l_orderkey\"], 4)",
+            "                UnresolvedShuffleExec: stages=[1], partitioning=UnknownPartitioning(2)",
+            "",
+            "QueryStageExec: job=<job>, stage=3, partitioning=UnknownPartitioning(1)",
+            "  MergeExec",
+            "    UnresolvedShuffleExec: stages=[2], partitioning=Hash([\"orderkey\"], 4)",
+            "",
+            "QueryStageExec: job=<job>, stage=4, partitioning=UnknownPartitioning(1)",
+            "  HashAggregateExec: groupBy=[\"orderkey\"], aggrExpr=[\"COUNT(l_quantity) [\\\"l_quantity\\\"]\"]",
+            "    UnresolvedShuffleExec: stages=[3], partitioning=UnknownPartitioning(1)",
+        ];
+        assert_eq!(explain, expected.join("\n"));
+
+        // without the rewrite the repartition's input becomes a stage of its own
+        let mut planner = test_planner()?;
+        planner.eliminate_redundant_shuffles = false;
+        let unoptimized_stages = planner.plan_query_stages(&job_id, plan.clone())?;
+        assert_eq!(unoptimized_stages.len(), stages.len() + 1);
+
+        let stage_output = execute_stages(&stages).await?;
+        let unoptimized_stage_output = execute_stages(&unoptimized_stages).await?;
+        let expected_rows = sorted_rows(&collect(plan).await?)?;
+        assert_eq!(
+            sorted_rows(&stage_output[&stages[3].stage_id].concat())?,
+            expected_rows
+        );
+        assert_eq!(
+            sorted_rows(&unoptimized_stage_output[&unoptimized_stages[4].stage_id].concat())?,
+            expected_rows
+        );
+
+        // every partition of stage 2 only holds the keys that hash partitioning on orderkey
+        // would have put there
+        let partial_output = &stage_output[&stages[1].stage_id];
+        let repartitioned = repartition_in_memory(partial_output, "orderkey", 4).await?;
+        assert_eq!(
+            partition_keys(partial_output),
+            partition_keys(&repartitioned)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn join_then_aggregate_with_different_partition_count() -> Result<(), BallistaError> {
+        let plan = join_then_aggregate_plan(3)?;
+        let stages =
+            test_planner()?.plan_query_stages(&Uuid::new_v4().to_string(), plan.clone())?;
+        println!("{}", format_stages(&stages)?);
+
+        // the aggregate requires a different number of partitions than the join produces, so
+        // the join runs in a stage of its own and is repartitioned by the consuming stage
+        assert_eq!(stages.len(), 5);
+        let stage_plan = format_plan(stages[2].as_ref(), 0)?;
+        assert!(stage_plan.contains("RepartitionExec: partitioning=Hash([\"orderkey\"], 3)"));
+        assert!(stage_plan.contains("UnresolvedShuffleExec: stages=[2]"));
+
+        let stage_output = execute_stages(&stages).await?;
+        assert_eq!(
+            sorted_rows(&stage_output[&stages[4].stage_id].concat())?,
+            sorted_rows(&collect(plan).await?)?
+        );
+
+        Ok(())
+    }
+
+    /// Joins orders and lineitem on the order key and counts line items per order, grouping by
+    /// the join key under a different name. DataFusion doesn't hash partition joins or
+    /// aggregates, so both inputs are explicitly repartitioned on the order key.
+    fn join_then_aggregate_plan(
+        partition_count: usize,
+    ) -> Result<Arc<dyn ExecutionPlan>, BallistaError> {
+        let mut ctx = datafusion_test_context("testdata")?;
+
+        let orders = ctx.table("orders")?;
+        let lineitem = ctx
+            .table("lineitem")?
+            .repartition(Partitioning::Hash(vec![col("l_orderkey")], 4))?;
+        let df = orders
+            .join(lineitem, JoinType::Inner, &["o_orderkey"], &["l_orderkey"])?
+            .select(&[col("l_orderkey").alias("orderkey"), col("l_quantity")])?
+            .repartition(Partitioning::Hash(vec![col("orderkey")], partition_count))?
+            .aggregate(&[col("orderkey")], &[count(col("l_quantity"))])?;
+
+        let plan = ctx.optimize(&df.to_logical_plan())?;
+        Ok(ctx.create_physical_plan(&plan)?)
+    }
+
+    fn test_planner() -> Result<DistributedPlanner, BallistaError> {
+        DistributedPlanner::try_new(vec![ExecutorMeta {
+            id: "".to_string(),
+            host: "".to_string(),
+            port: 0,
+        }])
+    }
+
+    fn format_stages(stages: &[Arc<QueryStageExec>]) -> Result<String, BallistaError> {
+        Ok(stages
+            .iter()
+            .map(|stage| format_plan(stage.as_ref(), 0))
+            .collect::<Result<Vec<String>, _>>()?
+            .join("\n\n"))
+    }
+
+    /// Executes the query stages in-process and returns the output partitions of each stage.
+    /// Like an executor task, each partition runs on its own deserialized copy of the stage
+    /// plan, reading the output of the stages it depends on.
+    async fn execute_stages(
+        stages: &[Arc<QueryStageExec>],
+    ) -> Result<HashMap<usize, Vec<Vec<RecordBatch>>>, BallistaError> {
+        let mut stage_output: HashMap<usize, Vec<Vec<RecordBatch>>> = HashMap::new();
+        for stage in stages {
+            let mut partitions = vec![];
+            for partition in 0..stage.output_partitioning.partition_count() {
+                let plan = roundtrip_operator(stage.children()[0].clone())?;
+                let plan = resolve_shuffles(plan, &stage_output)?;
+                let mut stream = plan.execute(partition).await?;
+                partitions.push(collect_stream(&mut stream).await?);
+            }
+            stage_output.insert(stage.stage_id, partitions);
+        }
+        Ok(stage_output)
+    }
+
+    fn resolve_shuffles(
+        plan: Arc<dyn ExecutionPlan>,
+        stage_output: &HashMap<usize, Vec<Vec<RecordBatch>>>,
+    ) -> Result<Arc<dyn ExecutionPlan>, BallistaError> {
+        if let Some(unresolved_shuffle) = plan.as_any().downcast_ref::<UnresolvedShuffleExec>() {
+            let partitions: Vec<Vec<RecordBatch>> = unresolved_shuffle
+                .query_stage_ids
+                .iter()
+                .flat_map(|id| stage_output[id].clone())
+                .collect();
+            Ok(Arc::new(MemoryExec::try_new(
+                &partitions,
+                unresolved_shuffle.schema(),
+                None,
+            )?))
+        } else if plan.children().is_empty() {
+            Ok(plan)
+        } else {
+            let children = plan
+                .children()
+                .into_iter()
+                .map(|child| resolve_shuffles(child, stage_output))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(plan.with_new_children(children)?)
+        }
+    }
+
+    /// Hash partitions the given batches on a column using a single RepartitionExec
+    async fn repartition_in_memory(
+        partitions: &[Vec<RecordBatch>],
+        column: &str,
+        partition_count: usize,
+    ) -> Result<Vec<Vec<RecordBatch>>, BallistaError> {
+        let batches = partitions.concat();
+        let input = MemoryExec::try_new(&[batches], partitions[0][0].schema(), None)?;
+        let repartition = Arc::new(RepartitionExec::try_new(
+            Arc::new(input),
+            PhysicalPartitioning::Hash(vec![Arc::new(Column::new(column))], partition_count),
+        )?);
+        futures::future::join_all((0..partition_count).map(|partition| {
+            let repartition = repartition.clone();
+            async move {
+                let mut stream = repartition.execute(partition).await?;
+                collect_stream(&mut stream).await
+            }
+        }))
+        .await
+        .into_iter()
+        .collect()
+    }
+
+    /// Returns the sorted values of the first column, which must be Int32, for each partition
+    fn partition_keys(partitions: &[Vec<RecordBatch>]) -> Vec<Vec<i32>> {
+        partitions
+            .iter()
+            .map(|batches| {
+                let mut keys: Vec<i32> = batches
+                    .iter()
+                    .flat_map(|batch| {
+                        let array = downcast_exec!(batch.column(0), Int32Array);
+                        array.values().to_vec()
+                    })
+                    .collect();
+                keys.sort_unstable();
+                keys
+            })
+            .collect()
+    }
+
+    fn sorted_rows(batches: &[RecordBatch]) -> Result<Vec<String>, BallistaError> {
+        let mut rows: Vec<String> = pretty_format_batches(batches)?
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+        rows.sort();
+        Ok(rows)
+    }
+
     fn roundtrip_operator(
         plan: Arc<dyn ExecutionPlan>,
     ) -> Result<Arc<dyn ExecutionPlan>, BallistaError> {
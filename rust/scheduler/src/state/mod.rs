@@ -202,7 +202,9 @@ impl SchedulerState {
                 > = HashMap::new();
                 for unresolved_shuffle in unresolved_shuffles {
                     for stage_id in unresolved_shuffle.query_stage_ids {
-                        for partition_id in 0..unresolved_shuffle.partition_count {
+                        for partition_id in
+                            0..unresolved_shuffle.output_partitioning.partition_count()
+                        {
                             let referenced_task = kvs
                                 .get(&get_task_status_key(
                                     namespace,